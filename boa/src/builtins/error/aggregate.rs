@@ -0,0 +1,147 @@
+//! This module implements the global `AggregateError` object.
+//!
+//! `AggregateError` is used to represent an error when several errors need to be
+//! reported together, e.g. by `Promise.any()` when every passed promise rejects.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-aggregate-error-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/AggregateError
+
+use crate::{
+    builtins::{
+        error::{set_error_cause, set_stack_trace},
+        iterable::iterable_to_list,
+        Array, BuiltIn,
+    },
+    context::StandardObjects,
+    object::{internal_methods::get_prototype_from_constructor, ConstructorBuilder, ObjectData},
+    profiler::BoaProfiler,
+    property::Attribute,
+    Context, JsResult, JsValue,
+};
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct AggregateError;
+
+impl BuiltIn for AggregateError {
+    const NAME: &'static str = "AggregateError";
+
+    const ATTRIBUTE: Attribute = Attribute::WRITABLE
+        .union(Attribute::NON_ENUMERABLE)
+        .union(Attribute::CONFIGURABLE);
+
+    fn init(context: &mut Context) -> JsValue {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let error_prototype = context.standard_objects().error_object().prototype();
+        let attribute = Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE;
+        let aggregate_error_object = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().aggregate_error_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .inherit(error_prototype.into())
+        .property("name", Self::NAME, attribute)
+        .property("message", "", attribute)
+        .build();
+
+        aggregate_error_object.into()
+    }
+}
+
+impl AggregateError {
+    /// The amount of arguments this function object takes.
+    pub(crate) const LENGTH: usize = 2;
+
+    /// Create a new error object.
+    pub(crate) fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardObjects::aggregate_error_object,
+            context,
+        )?;
+        let obj = context.construct_object();
+        obj.set_prototype_instance(prototype.into());
+        let this = JsValue::new(obj);
+
+        if let Some(message) = args.get(1) {
+            if !message.is_undefined() {
+                this.set_field("message", message.to_string(context)?, false, context)?;
+            }
+        }
+
+        this.set_data(ObjectData::error());
+        set_stack_trace(&this, context);
+        set_error_cause(&this, args.get(2), context)?;
+
+        let errors = args.get(0).cloned().unwrap_or(JsValue::undefined());
+        let errors = iterable_to_list(context, errors, None)?;
+        let errors = Array::create_array_from_list(errors, context);
+        this.as_object()
+            .expect("this was just constructed as an object")
+            .borrow_mut()
+            .insert_property(
+                "errors",
+                errors,
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            );
+
+        Ok(this)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{forward, Context};
+
+    #[test]
+    fn aggregate_error_collects_errors() {
+        let mut context = Context::default();
+        assert_eq!(
+            forward(
+                &mut context,
+                r#"
+                let err = new AggregateError([1, 2], "msg");
+                err.errors.length === 2 && err.errors[0] === 1 && err.errors[1] === 2
+                "#
+            ),
+            "true"
+        );
+    }
+
+    #[test]
+    fn aggregate_error_message_and_name() {
+        let mut context = Context::default();
+        assert_eq!(
+            forward(
+                &mut context,
+                r#"new AggregateError([], "msg").toString()"#
+            ),
+            "\"AggregateError: msg\""
+        );
+    }
+
+    #[test]
+    fn aggregate_error_errors_is_non_enumerable() {
+        let mut context = Context::default();
+        assert_eq!(
+            forward(
+                &mut context,
+                r#"
+                let err = new AggregateError([1, 2], "msg");
+                Object.keys(err).includes("errors");
+                "#
+            ),
+            "false"
+        );
+    }
+}
@@ -0,0 +1,129 @@
+//! This module implements an ergonomic, Rust-side view over JavaScript error objects.
+//!
+//! It mirrors the kind of accessor interface host bindings typically want when a
+//! `Context::eval` call comes back with a thrown value: a quick check for "is this an
+//! error", followed by reading its `name`/`message` without manually calling
+//! `get_field` and stringifying the result by hand.
+
+use crate::{object::JsObject, Context, JsResult, JsValue};
+
+/// Extension methods for inspecting values that may be native JavaScript error objects.
+///
+/// This is implemented for [`JsValue`], since that is what embedders typically hold
+/// after a failed [`Context::eval`](crate::Context::eval) call (the error is the `Err`
+/// payload's inner value once unwrapped).
+pub trait JsErrorExt {
+    /// Returns `true` if this value is an object tagged as a native error
+    /// (i.e. its [`ObjectData`](crate::object::ObjectData) was set via `ObjectData::error()`).
+    fn is_error(&self) -> bool;
+
+    /// Returns this value's object as a [`JsObject`] if it is tagged as a native error.
+    fn as_error(&self) -> Option<JsObject>;
+
+    /// Reads the error's `name`, falling back to `"Error"` per the spec default.
+    ///
+    /// Returns `Ok(None)` if this value is not an error object, or `Err` if reading or
+    /// stringifying `name` throws (e.g. a user-defined getter or `toString` throws) —
+    /// a thrown exception is a real failure and must not be confused with "not an error".
+    fn name(&self, context: &mut Context) -> JsResult<Option<String>>;
+
+    /// Reads the error's `message`, falling back to `""` per the spec default.
+    ///
+    /// Returns `Ok(None)` if this value is not an error object, or `Err` if reading or
+    /// stringifying `message` throws, for the same reason as [`JsErrorExt::name`].
+    fn message(&self, context: &mut Context) -> JsResult<Option<String>>;
+}
+
+impl JsErrorExt for JsValue {
+    fn is_error(&self) -> bool {
+        self.as_object()
+            .map(|obj| obj.borrow().is_error())
+            .unwrap_or(false)
+    }
+
+    fn as_error(&self) -> Option<JsObject> {
+        self.as_object()
+            .filter(|obj| obj.borrow().is_error())
+            .cloned()
+    }
+
+    fn name(&self, context: &mut Context) -> JsResult<Option<String>> {
+        if !self.is_error() {
+            return Ok(None);
+        }
+        let name = self.get_field("name", context)?;
+        if name.is_undefined() {
+            Ok(Some("Error".to_string()))
+        } else {
+            Ok(Some(name.to_string(context)?.to_string()))
+        }
+    }
+
+    fn message(&self, context: &mut Context) -> JsResult<Option<String>> {
+        if !self.is_error() {
+            return Ok(None);
+        }
+        let message = self.get_field("message", context)?;
+        if message.is_undefined() {
+            Ok(Some(String::new()))
+        } else {
+            Ok(Some(message.to_string(context)?.to_string()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::JsErrorExt;
+    use crate::{Context, JsValue};
+
+    #[test]
+    fn thrown_error_reports_is_error_name_and_message() {
+        let mut context = Context::default();
+        let err = context
+            .eval(r#"throw new TypeError("bad value");"#)
+            .expect_err("script should throw");
+
+        assert!(err.is_error());
+        assert!(err.as_error().is_some());
+        assert_eq!(
+            err.name(&mut context).unwrap(),
+            Some("TypeError".to_string())
+        );
+        assert_eq!(
+            err.message(&mut context).unwrap(),
+            Some("bad value".to_string())
+        );
+    }
+
+    #[test]
+    fn non_error_value_is_not_an_error() {
+        let mut context = Context::default();
+        let value = JsValue::new(42);
+
+        assert!(!value.is_error());
+        assert!(value.as_error().is_none());
+        assert_eq!(value.name(&mut context).unwrap(), None);
+        assert_eq!(value.message(&mut context).unwrap(), None);
+    }
+
+    #[test]
+    fn throwing_name_accessor_propagates_as_err() {
+        let mut context = Context::default();
+        let err = context
+            .eval(
+                r#"
+                let e = new Error("msg");
+                Object.defineProperty(e, "name", {
+                    get() {
+                        throw new RangeError("name getter exploded");
+                    },
+                });
+                e;
+                "#,
+            )
+            .expect("constructing the error value should not itself throw");
+
+        assert!(err.name(&mut context).is_err());
+    }
+}
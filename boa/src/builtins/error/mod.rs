@@ -0,0 +1,308 @@
+//! This module implements the global `Error` object family.
+//!
+//! Error objects are thrown as a result of runtime errors, as well as by user code via
+//! `throw`. The `Error` object can also be used as a base object for custom exceptions.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-error-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Error
+
+pub(crate) mod aggregate;
+mod jserror;
+pub(crate) mod reference;
+
+pub use jserror::JsErrorExt;
+pub(crate) use aggregate::AggregateError;
+pub(crate) use reference::ReferenceError;
+
+use crate::{
+    builtins::BuiltIn,
+    context::StandardObjects,
+    object::{internal_methods::get_prototype_from_constructor, ConstructorBuilder, ObjectData},
+    profiler::BoaProfiler,
+    property::Attribute,
+    Context, JsResult, JsValue,
+};
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Error;
+
+impl BuiltIn for Error {
+    const NAME: &'static str = "Error";
+
+    const ATTRIBUTE: Attribute = Attribute::WRITABLE
+        .union(Attribute::NON_ENUMERABLE)
+        .union(Attribute::CONFIGURABLE);
+
+    fn init(context: &mut Context) -> JsValue {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let attribute = Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE;
+        let error_object = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().error_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .property("name", Self::NAME, attribute)
+        .property("message", "", attribute)
+        .method(Self::to_string, "toString", 0)
+        .build();
+
+        error_object.into()
+    }
+}
+
+impl Error {
+    /// The amount of arguments this function object takes.
+    pub(crate) const LENGTH: usize = 1;
+
+    /// Create a new error object.
+    pub(crate) fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let prototype =
+            get_prototype_from_constructor(new_target, StandardObjects::error_object, context)?;
+        let obj = context.construct_object();
+        obj.set_prototype_instance(prototype.into());
+        let this = JsValue::new(obj);
+        if let Some(message) = args.get(0) {
+            if !message.is_undefined() {
+                this.set_field("message", message.to_string(context)?, false, context)?;
+            }
+        }
+
+        this.set_data(ObjectData::error());
+        set_stack_trace(&this, context);
+        set_error_cause(&this, args.get(1), context)?;
+        Ok(this)
+    }
+
+    /// `Error.prototype.toString()`
+    ///
+    /// Returns a string representing the specified `Error` object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-error.prototype.tostring
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Error/toString
+    pub(crate) fn to_string(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        if !this.is_object() {
+            return context.throw_type_error("Error.prototype.toString called on non-object");
+        }
+
+        let name = this.get_field("name", context)?;
+        let name = if name.is_undefined() {
+            "Error".to_string()
+        } else {
+            name.to_string(context)?.to_string()
+        };
+
+        let message = this.get_field("message", context)?;
+        let message = if message.is_undefined() {
+            String::new()
+        } else {
+            message.to_string(context)?.to_string()
+        };
+
+        let result = if name.is_empty() {
+            message
+        } else if message.is_empty() {
+            name
+        } else {
+            format!("{}: {}", name, message)
+        };
+
+        Ok(result.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{forward, Context};
+
+    #[test]
+    fn error_to_string() {
+        let mut context = Context::default();
+        assert_eq!(
+            forward(&mut context, r#"new Error("xyz").toString()"#),
+            "\"Error: xyz\""
+        );
+    }
+
+    #[test]
+    fn error_to_string_no_message() {
+        let mut context = Context::default();
+        assert_eq!(forward(&mut context, "new Error().toString()"), "\"Error\"");
+    }
+
+    #[test]
+    fn error_to_string_empty_name() {
+        let mut context = Context::default();
+        assert_eq!(
+            forward(
+                &mut context,
+                r#"
+                let err = new Error("xyz");
+                err.name = "";
+                err.toString();
+                "#
+            ),
+            "\"xyz\""
+        );
+    }
+
+    #[test]
+    fn error_cause() {
+        let mut context = Context::default();
+        assert_eq!(
+            forward(
+                &mut context,
+                r#"
+                let original = new Error("original");
+                let wrapped = new Error("wrapped", { cause: original });
+                wrapped.cause === original
+                "#
+            ),
+            "true"
+        );
+    }
+
+    #[test]
+    fn error_cause_omitted() {
+        let mut context = Context::default();
+        assert_eq!(
+            forward(&mut context, r#"new Error("xyz").cause"#),
+            "undefined"
+        );
+    }
+
+    #[test]
+    fn error_cause_is_non_enumerable() {
+        let mut context = Context::default();
+        assert_eq!(
+            forward(
+                &mut context,
+                r#"
+                let err = new Error("xyz", { cause: "why" });
+                Object.keys(err).includes("cause");
+                "#
+            ),
+            "false"
+        );
+    }
+
+    #[test]
+    fn error_stack_is_a_string() {
+        let mut context = Context::default();
+        assert_eq!(
+            forward(&mut context, "typeof new Error().stack"),
+            "\"string\""
+        );
+    }
+
+    #[test]
+    fn error_stack_is_non_enumerable() {
+        let mut context = Context::default();
+        assert_eq!(
+            forward(
+                &mut context,
+                r#"Object.keys(new Error("xyz")).includes("stack");"#
+            ),
+            "false"
+        );
+    }
+
+    #[test]
+    fn error_stack_lists_frames_innermost_first() {
+        let mut context = Context::default();
+        assert_eq!(
+            forward(
+                &mut context,
+                r#"
+                function inner() {
+                    return new Error("xyz").stack;
+                }
+                function outer() {
+                    return inner();
+                }
+                let frames = outer().split("\n");
+                frames[0].includes("inner") && frames[1].includes("outer");
+                "#
+            ),
+            "true"
+        );
+    }
+}
+
+/// Walks the interpreter's current call frames and records them on `this` as a
+/// non-enumerable `stack` property, joining one frame per line.
+///
+/// Each native error constructor calls this right after tagging the new object with
+/// [`ObjectData::error`], so the trace reflects the call stack at the point the error
+/// was created rather than where it is eventually read from.
+pub(crate) fn set_stack_trace(this: &JsValue, context: &Context) {
+    let trace = context
+        .vm
+        .frames
+        .iter()
+        .rev()
+        .map(|frame| match frame.code.position_at(frame.pc) {
+            Some(position) => format!(
+                "{} ({}:{})",
+                frame.code.name(),
+                position.line_number(),
+                position.column_number()
+            ),
+            None => frame.code.name().to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    if let Some(obj) = this.as_object() {
+        obj.borrow_mut().insert_property(
+            "stack",
+            trace,
+            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        );
+    }
+}
+
+/// Implements the ES2022 `cause` option: if `options` is an object with an own `cause`
+/// property, that value is copied onto `this` as a non-enumerable own `cause` property.
+///
+/// Shared by every native error constructor so that `new XError("msg", { cause })`
+/// behaves consistently across `Error` and its subtypes. Callers pass the argument they
+/// consider to be the options bag, since its position varies (e.g. it sits after the
+/// `errors` iterable and `message` for `AggregateError`).
+pub(crate) fn set_error_cause(
+    this: &JsValue,
+    options: Option<&JsValue>,
+    context: &mut Context,
+) -> JsResult<()> {
+    if let Some(options) = options {
+        if options.is_object() && options.has_field("cause") {
+            let cause = options.get_field("cause", context)?;
+            if let Some(obj) = this.as_object() {
+                obj.borrow_mut().insert_property(
+                    "cause",
+                    cause,
+                    Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+                );
+            }
+        }
+    }
+    Ok(())
+}
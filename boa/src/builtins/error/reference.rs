@@ -10,7 +10,10 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ReferenceError
 
 use crate::{
-    builtins::BuiltIn,
+    builtins::{
+        error::{set_error_cause, set_stack_trace},
+        BuiltIn,
+    },
     context::StandardObjects,
     object::{internal_methods::get_prototype_from_constructor, ConstructorBuilder, ObjectData},
     profiler::BoaProfiler,
@@ -73,6 +76,8 @@ impl ReferenceError {
         // This value is used by console.log and other routines to match Object type
         // to its Javascript Identifier (global constructor method name)
         this.set_data(ObjectData::error());
+        set_stack_trace(&this, context);
+        set_error_cause(&this, args.get(1), context)?;
         Ok(this)
     }
 }
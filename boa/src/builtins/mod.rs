@@ -0,0 +1,35 @@
+//! This module contains the global builtins and the machinery used to instantiate them
+//! onto a fresh [`Context`](crate::Context)'s global object.
+
+pub(crate) mod error;
+
+pub(crate) use error::{AggregateError, Error, ReferenceError};
+pub use error::JsErrorExt;
+
+use crate::{property::Attribute, Context, JsValue};
+
+/// A JavaScript intrinsic that can be built and bound onto the global object.
+pub(crate) trait BuiltIn {
+    /// The name this intrinsic is bound under on the global object.
+    const NAME: &'static str;
+
+    /// The property attributes used when defining `NAME` on the global object.
+    const ATTRIBUTE: Attribute;
+
+    /// Builds the intrinsic and returns the value to bind to `NAME`.
+    fn init(context: &mut Context) -> JsValue;
+}
+
+/// Instantiates every global builtin and defines it as a property of the global object.
+pub(crate) fn init(context: &mut Context) {
+    macro_rules! globals {
+        ($($builtin:ty),* $(,)?) => {
+            $(
+                let value = <$builtin as BuiltIn>::init(context);
+                context.register_global_property(<$builtin as BuiltIn>::NAME, value, <$builtin as BuiltIn>::ATTRIBUTE);
+            )*
+        };
+    }
+
+    globals!(Error, ReferenceError, AggregateError);
+}
@@ -0,0 +1,54 @@
+//! Standard built-in object slots threaded through the [`Context`](crate::Context).
+//!
+//! [`StandardObjects`] holds the constructor/prototype pair for every intrinsic that
+//! other builtins need fast, type-safe access to — e.g. to inherit from when building
+//! their own prototype, or to use as the default prototype when `new.target` doesn't
+//! supply one via `get_prototype_from_constructor`.
+
+use crate::object::JsObject;
+
+/// A constructor function paired with the prototype object instances created through
+/// it should inherit from.
+#[derive(Debug, Clone)]
+pub struct StandardConstructor {
+    constructor: JsObject,
+    prototype: JsObject,
+}
+
+impl StandardConstructor {
+    /// Returns the constructor function object.
+    pub fn constructor(&self) -> JsObject {
+        self.constructor.clone()
+    }
+
+    /// Returns the prototype instances created through this constructor inherit from.
+    pub fn prototype(&self) -> JsObject {
+        self.prototype.clone()
+    }
+}
+
+/// Holds the [`StandardConstructor`] for every intrinsic the engine needs to reach
+/// outside of a full global object property lookup.
+#[derive(Debug, Clone)]
+pub struct StandardObjects {
+    error_object: StandardConstructor,
+    reference_error_object: StandardConstructor,
+    aggregate_error_object: StandardConstructor,
+}
+
+impl StandardObjects {
+    /// The `Error` constructor/prototype pair.
+    pub fn error_object(&self) -> &StandardConstructor {
+        &self.error_object
+    }
+
+    /// The `ReferenceError` constructor/prototype pair.
+    pub fn reference_error_object(&self) -> &StandardConstructor {
+        &self.reference_error_object
+    }
+
+    /// The `AggregateError` constructor/prototype pair.
+    pub fn aggregate_error_object(&self) -> &StandardConstructor {
+        &self.aggregate_error_object
+    }
+}
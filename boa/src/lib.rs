@@ -0,0 +1,8 @@
+//! This is an ECMAScript engine implemented in Rust.
+
+#![doc(html_logo_url = "https://raw.githubusercontent.com/boa-dev/boa/main/assets/logo.svg")]
+
+pub mod builtins;
+pub mod context;
+
+pub use crate::{builtins::JsErrorExt, context::Context};